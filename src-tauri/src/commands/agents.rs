@@ -1,6 +1,5 @@
 use anyhow::Result;
 use chrono;
-use dirs;
 use log::{debug, error, info, warn};
 use reqwest;
 use rusqlite::{params, Connection, Result as SqliteResult};
@@ -168,9 +167,8 @@ impl AgentRunMetrics {
 
 /// Read JSONL content from a session file
 pub async fn read_session_jsonl(session_id: &str, project_path: &str) -> Result<String, String> {
-    let claude_dir = dirs::home_dir()
+    let claude_dir = crate::commands::claude::resolve_claude_dir()
         .ok_or("Failed to get home directory")?
-        .join(".claude")
         .join("projects");
 
     // Encode project path to match Claude Code's directory naming
@@ -1366,9 +1364,8 @@ pub async fn get_session_output(
     }
 
     // Get the Claude directory
-    let claude_dir = dirs::home_dir()
-        .ok_or("Failed to get home directory")?
-        .join(".claude");
+    let claude_dir = crate::commands::claude::resolve_claude_dir()
+        .ok_or("Failed to get home directory")?;
 
     // Find the correct project directory by searching for the session file
     let projects_dir = claude_dir.join("projects");
@@ -1459,8 +1456,8 @@ pub async fn stream_session_output(
 
     // Spawn a task to monitor the file
     tokio::spawn(async move {
-        let claude_dir = match dirs::home_dir() {
-            Some(home) => home.join(".claude").join("projects"),
+        let claude_dir = match crate::commands::claude::resolve_claude_dir() {
+            Some(dir) => dir.join("projects"),
             None => return,
         };
 
@@ -1935,9 +1932,8 @@ pub async fn load_agent_session_history(
 ) -> Result<Vec<serde_json::Value>, String> {
     log::info!("Loading agent session history for session: {}", session_id);
 
-    let claude_dir = dirs::home_dir()
-        .ok_or("Failed to get home directory")?
-        .join(".claude");
+    let claude_dir = crate::commands::claude::resolve_claude_dir()
+        .ok_or("Failed to get home directory")?;
 
     let projects_dir = claude_dir.join("projects");
 
@@ -134,13 +134,37 @@ fn find_claude_binary(app_handle: &AppHandle) -> Result<String, String> {
     crate::claude_binary::find_claude_binary(app_handle)
 }
 
-/// Gets the path to the ~/.claude directory
+/// Resolves the Claude config directory.
+///
+/// `OPCODE_CLAUDE_DIR` takes precedence over `CLAUDE_CONFIG_DIR`; when neither is
+/// set the directory falls back to `~/.claude`. The path is not required to exist.
+pub fn resolve_claude_dir() -> Option<PathBuf> {
+    resolve_claude_dir_from(
+        std::env::var("OPCODE_CLAUDE_DIR").ok(),
+        std::env::var("CLAUDE_CONFIG_DIR").ok(),
+        dirs::home_dir(),
+    )
+}
+
+fn resolve_claude_dir_from(
+    opcode_dir: Option<String>,
+    config_dir: Option<String>,
+    home_dir: Option<PathBuf>,
+) -> Option<PathBuf> {
+    opcode_dir
+        .into_iter()
+        .chain(config_dir)
+        .find(|dir| !dir.trim().is_empty())
+        .map(PathBuf::from)
+        .or_else(|| home_dir.map(|home| home.join(".claude")))
+}
+
+/// Gets the path to the Claude config directory (`~/.claude` unless overridden)
 fn get_claude_dir() -> Result<PathBuf> {
-    dirs::home_dir()
+    resolve_claude_dir()
         .context("Could not find home directory")?
-        .join(".claude")
         .canonicalize()
-        .context("Could not find ~/.claude directory")
+        .context("Could not find Claude config directory")
 }
 
 /// Gets the actual project path by reading the cwd from the JSONL entries
@@ -190,6 +214,21 @@ fn decode_project_path(encoded: &str) -> String {
     encoded.replace('-', "/")
 }
 
+/// The path a project's sessions ran in, decoded from its directory name when no session records it
+pub(crate) fn resolve_project_path(project_dir: &PathBuf, project_id: &str) -> String {
+    match get_project_path_from_sessions(project_dir) {
+        Ok(path) => path,
+        Err(e) => {
+            log::warn!(
+                "Failed to get project path from sessions for {}: {}, falling back to decode",
+                project_id,
+                e
+            );
+            decode_project_path(project_id)
+        }
+    }
+}
+
 /// Extracts the first valid user message from a JSONL file
 fn extract_first_user_message(jsonl_path: &PathBuf) -> (Option<String>, Option<String>) {
     let file = match fs::File::open(jsonl_path) {
@@ -482,17 +521,7 @@ pub async fn get_project_sessions(project_id: String) -> Result<Vec<Session>, St
     }
 
     // Get the actual project path from JSONL files
-    let project_path = match get_project_path_from_sessions(&project_dir) {
-        Ok(path) => path,
-        Err(e) => {
-            log::warn!(
-                "Failed to get project path from sessions for {}: {}, falling back to decode",
-                project_id,
-                e
-            );
-            decode_project_path(&project_id)
-        }
-    };
+    let project_path = resolve_project_path(&project_dir, &project_id);
 
     let mut sessions = Vec::new();
 
@@ -2186,6 +2215,34 @@ mod tests {
     use std::io::Write;
     use tempfile::TempDir;
 
+    #[test]
+    fn test_resolve_claude_dir_with_and_without_override() {
+        let home = Some(PathBuf::from("/home/user"));
+
+        assert_eq!(
+            resolve_claude_dir_from(None, None, home.clone()),
+            Some(PathBuf::from("/home/user/.claude"))
+        );
+        assert_eq!(
+            resolve_claude_dir_from(None, Some("/opt/claude".into()), home.clone()),
+            Some(PathBuf::from("/opt/claude"))
+        );
+        assert_eq!(
+            resolve_claude_dir_from(
+                Some("/srv/opcode-claude".into()),
+                Some("/opt/claude".into()),
+                home.clone()
+            ),
+            Some(PathBuf::from("/srv/opcode-claude"))
+        );
+        // Empty values are treated as unset
+        assert_eq!(
+            resolve_claude_dir_from(Some(String::new()), None, home),
+            Some(PathBuf::from("/home/user/.claude"))
+        );
+        assert_eq!(resolve_claude_dir_from(None, None, None), None);
+    }
+
     /// Helper function to create a test session file
     fn create_test_session_file(
         dir: &PathBuf,
@@ -145,6 +145,61 @@ pub fn discover_system_claude_processes() -> Vec<ProcessInfo> {
     discovered_processes
 }
 
+/// A running MCP server process, as seen by `ps`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct McpServerProcess {
+    pub pid: u32,
+    pub ppid: u32,
+    pub command: String,
+    /// Reparented to init, i.e. the Claude process that started it is gone
+    pub orphaned: bool,
+}
+
+/// Pick the `mcp-server` processes out of `ps -o pid= -o ppid= -o args=` output
+pub fn parse_mcp_server_processes(ps_output: &str) -> Vec<McpServerProcess> {
+    ps_output
+        .lines()
+        .filter_map(|line| {
+            let mut tokens = line.split_whitespace();
+            let pid: u32 = tokens.next()?.parse().ok()?;
+            let ppid: u32 = tokens.next()?.parse().ok()?;
+            let command = tokens.collect::<Vec<_>>().join(" ");
+            if !command.contains("mcp-server") {
+                return None;
+            }
+            Some(McpServerProcess {
+                pid,
+                ppid,
+                command,
+                orphaned: ppid == 1,
+            })
+        })
+        .collect()
+}
+
+/// Discover running MCP server processes of the current user.
+/// These are the auxiliary processes `discover_system_claude_processes` skips.
+pub fn discover_mcp_server_processes() -> Vec<McpServerProcess> {
+    if !(cfg!(target_os = "linux") || cfg!(target_os = "macos")) {
+        return Vec::new();
+    }
+
+    let output = Command::new("ps")
+        .args(["-u", std::env::var("USER").unwrap_or_else(|_| String::from("")).as_str(), "-o", "pid=", "-o", "ppid=", "-o", "args="])
+        .output();
+    match output {
+        Ok(output) if output.status.success() => parse_mcp_server_processes(&String::from_utf8_lossy(&output.stdout)),
+        Ok(output) => {
+            log::error!("ps exited with {} while discovering MCP servers", output.status);
+            Vec::new()
+        }
+        Err(e) => {
+            log::error!("Failed to discover MCP server processes: {}", e);
+            Vec::new()
+        }
+    }
+}
+
 #[tauri::command]
 pub async fn get_all_processes(
     registry: State<'_, ProcessRegistryState>,
@@ -1,5 +1,4 @@
 use anyhow::{Context, Result};
-use dirs;
 use log::{debug, error, info};
 use serde::{Deserialize, Serialize};
 use std::fs;
@@ -281,8 +280,8 @@ pub async fn slash_commands_list(
     }
 
     // Load user commands
-    if let Some(home_dir) = dirs::home_dir() {
-        let user_commands_dir = home_dir.join(".claude").join("commands");
+    if let Some(claude_dir) = crate::commands::claude::resolve_claude_dir() {
+        let user_commands_dir = claude_dir.join("commands");
         if user_commands_dir.exists() {
             debug!("Scanning user commands at: {:?}", user_commands_dir);
 
@@ -360,9 +359,8 @@ pub async fn slash_command_save(
             return Err("Project path required for project scope".to_string());
         }
     } else {
-        dirs::home_dir()
+        crate::commands::claude::resolve_claude_dir()
             .ok_or_else(|| "Could not find home directory".to_string())?
-            .join(".claude")
             .join("commands")
     };
 
@@ -376,76 +376,14 @@ impl ProcessRegistry {
 
     /// Kill a process by PID using system commands (fallback method)
     pub fn kill_process_by_pid(&self, run_id: i64, pid: u32) -> Result<bool, String> {
-        use log::{error, info, warn};
-
-        info!("Attempting to kill process {} by PID {}", run_id, pid);
-
-        let kill_result = if cfg!(target_os = "windows") {
-            std::process::Command::new("taskkill")
-                .args(["/F", "/PID", &pid.to_string()])
-                .output()
-        } else {
-            // First try SIGTERM
-            let term_result = std::process::Command::new("kill")
-                .args(["-TERM", &pid.to_string()])
-                .output();
-
-            match &term_result {
-                Ok(output) if output.status.success() => {
-                    info!("Sent SIGTERM to PID {}", pid);
-                    // Give it 2 seconds to exit gracefully
-                    std::thread::sleep(std::time::Duration::from_secs(2));
-
-                    // Check if still running
-                    let check_result = std::process::Command::new("kill")
-                        .args(["-0", &pid.to_string()])
-                        .output();
-
-                    if let Ok(output) = check_result {
-                        if output.status.success() {
-                            // Still running, send SIGKILL
-                            warn!(
-                                "Process {} still running after SIGTERM, sending SIGKILL",
-                                pid
-                            );
-                            std::process::Command::new("kill")
-                                .args(["-KILL", &pid.to_string()])
-                                .output()
-                        } else {
-                            term_result
-                        }
-                    } else {
-                        term_result
-                    }
-                }
-                _ => {
-                    // SIGTERM failed, try SIGKILL directly
-                    warn!("SIGTERM failed for PID {}, trying SIGKILL", pid);
-                    std::process::Command::new("kill")
-                        .args(["-KILL", &pid.to_string()])
-                        .output()
-                }
-            }
-        };
+        log::info!("Attempting to kill process {} by PID {}", run_id, pid);
 
-        match kill_result {
-            Ok(output) => {
-                if output.status.success() {
-                    info!("Successfully killed process with PID {}", pid);
-                    // Remove from registry
-                    self.unregister_process(run_id)?;
-                    Ok(true)
-                } else {
-                    let error_msg = String::from_utf8_lossy(&output.stderr);
-                    warn!("Failed to kill PID {}: {}", pid, error_msg);
-                    Ok(false)
-                }
-            }
-            Err(e) => {
-                error!("Failed to execute kill command for PID {}: {}", pid, e);
-                Err(format!("Failed to execute kill command: {}", e))
-            }
+        let killed = terminate_pid(pid)?;
+        if killed {
+            // Remove from registry
+            self.unregister_process(run_id)?;
         }
+        Ok(killed)
     }
 
     /// Check if a process is still running by trying to get its status
@@ -550,3 +488,74 @@ impl Default for ProcessRegistryState {
         Self(Arc::new(ProcessRegistry::new()))
     }
 }
+
+/// Kill a process by PID using system commands: SIGTERM, then SIGKILL if it is still
+/// running two seconds later (`taskkill /F` on Windows). Doesn't touch any registry.
+pub fn terminate_pid(pid: u32) -> Result<bool, String> {
+    use log::{error, info, warn};
+
+    let kill_result = if cfg!(target_os = "windows") {
+        std::process::Command::new("taskkill")
+            .args(["/F", "/PID", &pid.to_string()])
+            .output()
+    } else {
+        // First try SIGTERM
+        let term_result = std::process::Command::new("kill")
+            .args(["-TERM", &pid.to_string()])
+            .output();
+
+        match &term_result {
+            Ok(output) if output.status.success() => {
+                info!("Sent SIGTERM to PID {}", pid);
+                // Give it 2 seconds to exit gracefully
+                std::thread::sleep(std::time::Duration::from_secs(2));
+
+                // Check if still running
+                let check_result = std::process::Command::new("kill")
+                    .args(["-0", &pid.to_string()])
+                    .output();
+
+                if let Ok(output) = check_result {
+                    if output.status.success() {
+                        // Still running, send SIGKILL
+                        warn!(
+                            "Process {} still running after SIGTERM, sending SIGKILL",
+                            pid
+                        );
+                        std::process::Command::new("kill")
+                            .args(["-KILL", &pid.to_string()])
+                            .output()
+                    } else {
+                        term_result
+                    }
+                } else {
+                    term_result
+                }
+            }
+            _ => {
+                // SIGTERM failed, try SIGKILL directly
+                warn!("SIGTERM failed for PID {}, trying SIGKILL", pid);
+                std::process::Command::new("kill")
+                    .args(["-KILL", &pid.to_string()])
+                    .output()
+            }
+        }
+    };
+
+    match kill_result {
+        Ok(output) => {
+            if output.status.success() {
+                info!("Successfully killed process with PID {}", pid);
+                Ok(true)
+            } else {
+                let error_msg = String::from_utf8_lossy(&output.stderr);
+                warn!("Failed to kill PID {}: {}", pid, error_msg);
+                Ok(false)
+            }
+        }
+        Err(e) => {
+            error!("Failed to execute kill command for PID {}: {}", pid, e);
+            Err(format!("Failed to execute kill command: {}", e))
+        }
+    }
+}
@@ -21,7 +21,7 @@ struct Args {
 
 #[tokio::main]
 async fn main() {
-    env_logger::init();
+    web_server::init_web_logger();
 
     let args = Args::parse();
 
@@ -31,7 +31,7 @@ async fn main() {
         args.host, args.port
     );
 
-    if let Err(e) = web_server::start_web_mode(Some(args.port)).await {
+    if let Err(e) = web_server::start_web_mode(Some(args.host), Some(args.port)).await {
         eprintln!("❌ Failed to start web server: {}", e);
         std::process::exit(1);
     }
@@ -453,6 +453,13 @@ export function getEnvironmentInfo() {
   };
 }
 
+/**
+ * WebSocket frame types that carry a raw stream-json line in `content`.
+ * The server classifies assistant/user messages into typed frames and sends
+ * everything else as plain `output`.
+ */
+const CLAUDE_OUTPUT_FRAME_TYPES = ['output', 'assistant_text', 'tool_use', 'tool_result', 'thinking'];
+
 /**
  * Session persistence utilities for WebSocket connections
  */
@@ -551,7 +558,7 @@ async function handleStreamingCommand<T>(command: string, params?: any): Promise
       // Update status to connected when we receive output
       networkStatusManager.setStatus('connected');
 
-      if (message.type === 'output') {
+      if (CLAUDE_OUTPUT_FRAME_TYPES.includes(message.type)) {
         try {
           const claudeMessage = typeof message.content === 'string'
             ? JSON.parse(message.content)
@@ -706,7 +713,7 @@ export async function apiCall<T>(command: string, params?: any): Promise<T> {
     method = 'POST';
   } else if (command.startsWith('update_') || command.startsWith('save_') || command.startsWith('set_')) {
     method = 'PUT';
-  } else if (command.startsWith('delete_') || command.startsWith('remove_') || command.startsWith('kill_')) {
+  } else if (command.startsWith('delete_') || command.startsWith('remove_') || command.startsWith('kill_') || command === 'cancel_claude_execution') {
     method = 'DELETE';
   }
   